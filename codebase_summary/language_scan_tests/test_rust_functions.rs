@@ -1,4 +1,11 @@
 // Rust test functions for breadcrumb detection validation
+//
+// These fixtures are also what the crate's own scan (`src/parser.rs`) and
+// language server (`src/lsp.rs`) diagnose. Only the items preceded by a
+// `///` doc comment, a `#[doc ...]` attribute or a `#[breadcrumb(...)]`
+// attribute count as breadcrumbed; every other function and method here,
+// `basic_function`, `undocumented_function` and
+// `TestStruct::undocumented_method` included, is reported as missing one.
 
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -12,6 +19,10 @@ fn function_with_params(param1: &str, param2: i32) -> String {
 }
 
 // Function without breadcrumb documentation
+//
+// `--format json` reports it with the diagnostic's `span` covering this
+// signature and its `label` span on the name; the emitter is
+// `src/diagnostics.rs`.
 fn undocumented_function() -> bool {
     true
 }
@@ -74,6 +85,56 @@ const fn const_function(x: i32, y: i32) -> i32 {
     x + y
 }
 
+pub(crate) fn crate_visible_function(input: i32) -> i32 {
+    input * 2
+}
+
+unsafe fn unsafe_function(ptr: *const i32) -> i32 {
+    *ptr
+}
+
+extern "C" fn extern_function(x: i32) -> i32 {
+    x
+}
+
+pub async fn combined_modifiers_function(input: String) -> String {
+    input
+}
+
+// Raw identifier used as a function name
+fn r#fn(r#match: bool) -> bool {
+    r#match
+}
+
+fn nested_generic_function<T: Clone>(input: Vec<HashMap<String, T>>) -> usize {
+    input.len()
+}
+
+/// Documented via a `///` doc block instead of a breadcrumb comment marker.
+fn doc_comment_function() -> bool {
+    true
+}
+
+#[doc = "Documented via a `#[doc = \"...\"]` attribute."]
+fn doc_attribute_function() -> bool {
+    true
+}
+
+#[doc(alias = "lookup_value")]
+#[doc(alias = "find_value")]
+pub fn aliased_function(key: &str) -> Option<String> {
+    Some(key.to_string())
+}
+
+// A custom breadcrumb attribute stacked above a doc alias, with a blank
+// line before the signature that must not break attribute association.
+#[breadcrumb(id = "custom-fn")]
+#[doc(alias = "custom_lookup")]
+
+fn custom_breadcrumb_function() -> bool {
+    true
+}
+
 fn main() {
     println!("Rust function tests ready");
     let _ = basic_function();
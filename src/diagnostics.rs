@@ -0,0 +1,121 @@
+//! Structured, machine-readable breadcrumb diagnostics.
+//!
+//! Modeled on `rustc`'s own diagnostic shape (a primary message, a label
+//! anchored on the offending span, and a suggestion) rather than the plain
+//! text the CLI used to print, so CI pipelines and editors can consume
+//! results uniformly instead of re-parsing human output.
+
+use serde::Serialize;
+
+use crate::parser::{Item, ItemKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SpanJson {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Label {
+    pub span: SpanJson,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    pub file: String,
+    pub span: SpanJson,
+    pub message: String,
+    pub label: Label,
+    pub severity: Severity,
+    pub suggestion: String,
+}
+
+/// Builds one [`Diagnostic`] per item in `items` that is missing a
+/// breadcrumb, keyed to `file`.
+pub fn diagnostics_for_file(file: &str, items: &[Item]) -> Vec<Diagnostic> {
+    items
+        .iter()
+        .filter(|item| !item.breadcrumb.is_present())
+        .map(|item| Diagnostic {
+            file: file.to_string(),
+            span: SpanJson { start: item.sig_span.start, end: item.sig_span.end },
+            message: format!("`{}` is missing a breadcrumb", item.name),
+            label: Label {
+                span: SpanJson { start: item.name_span.start, end: item.name_span.end },
+                text: "missing breadcrumb".to_string(),
+            },
+            severity: Severity::Warning,
+            suggestion: breadcrumb_suggestion(item),
+        })
+        .collect()
+}
+
+fn breadcrumb_suggestion(item: &Item) -> String {
+    format!(
+        "/// Document what this {} does and why.",
+        item_kind_label(item.kind)
+    )
+}
+
+fn item_kind_label(kind: ItemKind) -> &'static str {
+    match kind {
+        ItemKind::Function => "function",
+        ItemKind::InherentMethod => "method",
+        ItemKind::TraitImplMethod => "trait-impl method",
+        ItemKind::TraitMethodSignature => "trait method",
+    }
+}
+
+pub fn render_json(diagnostics: &[Diagnostic]) -> String {
+    serde_json::to_string_pretty(diagnostics).unwrap_or_else(|_| "[]".to_string())
+}
+
+pub fn render_human(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::new();
+    for d in diagnostics {
+        out.push_str(&format!("{}:{}: {}\n", d.file, d.span.start, d.message));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    #[test]
+    fn emits_one_diagnostic_per_undocumented_item() {
+        let src = "fn undocumented_function() -> bool {\n    true\n}\n";
+        let items = parser::scan(src);
+        let diags = diagnostics_for_file("a.rs", &items);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "`undocumented_function` is missing a breadcrumb");
+        assert_eq!(diags[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn documented_items_produce_no_diagnostic() {
+        let src = "/// Already documented.\nfn documented_function() -> bool {\n    true\n}\n";
+        let items = parser::scan(src);
+        assert!(diagnostics_for_file("a.rs", &items).is_empty());
+    }
+
+    #[test]
+    fn json_output_round_trips_through_serde() {
+        let src = "fn undocumented_function() -> bool {\n    true\n}\n";
+        let items = parser::scan(src);
+        let diags = diagnostics_for_file("a.rs", &items);
+        let json = render_json(&diags);
+        let parsed: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["file"], "a.rs");
+        assert_eq!(parsed[0]["severity"], "warning");
+        assert!(parsed[0]["label"]["text"].as_str().unwrap().contains("missing breadcrumb"));
+    }
+}
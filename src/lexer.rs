@@ -0,0 +1,378 @@
+//! Minimal Rust tokenizer used as the front-end for signature parsing.
+//!
+//! This is not a full Rust lexer: it only distinguishes what the parser
+//! needs to locate and classify function signatures — identifiers
+//! (including `r#raw` ones), the punctuation that bounds generics/params,
+//! `->` and `::`, string/char literals (skipped whole so their contents
+//! never get mistaken for code), doc/line comments, and attributes
+//! (`#[...]`, captured whole so the parser can inspect `doc`/custom
+//! breadcrumb attributes without its own bracket-matching pass).
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    Ident { text: String, raw: bool },
+    Punct(char),
+    Arrow,
+    PathSep,
+    DocComment(String),
+    LineComment,
+    Literal,
+    /// Raw text between `#[`/`#![` and the matching `]`, attribute path and
+    /// arguments included, e.g. `doc(alias = "lookup_value")`. `inner` is
+    /// true for `#![...]`, which documents the *enclosing* scope rather
+    /// than the item that follows it.
+    Attribute { text: String, inner: bool },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+pub fn tokenize(src: &str) -> Vec<Token> {
+    let bytes = src.as_bytes();
+    let len = bytes.len();
+    let mut i = 0usize;
+    let mut tokens = Vec::new();
+
+    while i < len {
+        // Safe to unwrap: `i` only ever lands on a char boundary, since
+        // every branch below advances it by a whole char (ASCII fast paths)
+        // or by `char::len_utf8()` (identifiers).
+        let c = char_at(src, i).unwrap();
+
+        if c.is_whitespace() {
+            i += c.len_utf8();
+            continue;
+        }
+
+        if c == '/' && peek(bytes, i + 1) == Some('/') {
+            let start = i;
+            let is_doc = peek(bytes, i + 2) == Some('/') && peek(bytes, i + 3) != Some('/');
+            let mut j = i + 2;
+            while j < len && bytes[j] as char != '\n' {
+                j += 1;
+            }
+            let kind = if is_doc {
+                TokenKind::DocComment(src[(start + 3)..j].trim().to_string())
+            } else {
+                TokenKind::LineComment
+            };
+            tokens.push(Token { kind, start, end: j });
+            i = j;
+            continue;
+        }
+
+        if c == '/' && peek(bytes, i + 1) == Some('*') {
+            let start = i;
+            let mut depth = 1usize;
+            let mut j = i + 2;
+            while j < len && depth > 0 {
+                if peek(bytes, j) == Some('/') && peek(bytes, j + 1) == Some('*') {
+                    depth += 1;
+                    j += 2;
+                } else if peek(bytes, j) == Some('*') && peek(bytes, j + 1) == Some('/') {
+                    depth -= 1;
+                    j += 2;
+                } else {
+                    j += 1;
+                }
+            }
+            tokens.push(Token { kind: TokenKind::LineComment, start, end: j });
+            i = j;
+            continue;
+        }
+
+        if c == '#' {
+            let start = i;
+            let mut j = i + 1;
+            let is_inner = peek(bytes, j) == Some('!');
+            if is_inner {
+                j += 1;
+            }
+            if peek(bytes, j) == Some('[') {
+                let mut depth = 0usize;
+                while j < len {
+                    match bytes[j] as char {
+                        '[' => depth += 1,
+                        ']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                j += 1;
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                    j += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Attribute { text: inner_attribute_text(src, start, j), inner: is_inner },
+                    start,
+                    end: j,
+                });
+                i = j;
+                continue;
+            }
+            tokens.push(Token { kind: TokenKind::Punct('#'), start, end: i + 1 });
+            i += 1;
+            continue;
+        }
+
+        if c == '"' || is_string_prefix(bytes, i) {
+            let end = lex_string(bytes, i);
+            tokens.push(Token { kind: TokenKind::Literal, start: i, end });
+            i = end;
+            continue;
+        }
+
+        if c == '\'' {
+            if let Some(end) = try_lex_char_literal(bytes, i) {
+                tokens.push(Token { kind: TokenKind::Literal, start: i, end });
+                i = end;
+            } else {
+                // Lifetime marker (`'a`, `'static`): skip the quote and let
+                // the following identifier lex normally.
+                i += 1;
+            }
+            continue;
+        }
+
+        if c == 'r' && peek(bytes, i + 1) == Some('#') && is_ident_start_opt(char_at(src, i + 2)) {
+            let start = i + 2;
+            let j = scan_ident_continue(src, start);
+            tokens.push(Token {
+                kind: TokenKind::Ident { text: src[start..j].to_string(), raw: true },
+                start: i,
+                end: j,
+            });
+            i = j;
+            continue;
+        }
+
+        if is_ident_start(c) {
+            let start = i;
+            let j = scan_ident_continue(src, start + c.len_utf8());
+            tokens.push(Token {
+                kind: TokenKind::Ident { text: src[start..j].to_string(), raw: false },
+                start,
+                end: j,
+            });
+            i = j;
+            continue;
+        }
+
+        if c == '-' && peek(bytes, i + 1) == Some('>') {
+            tokens.push(Token { kind: TokenKind::Arrow, start: i, end: i + 2 });
+            i += 2;
+            continue;
+        }
+
+        if c == ':' && peek(bytes, i + 1) == Some(':') {
+            tokens.push(Token { kind: TokenKind::PathSep, start: i, end: i + 2 });
+            i += 2;
+            continue;
+        }
+
+        tokens.push(Token { kind: TokenKind::Punct(c), start: i, end: i + c.len_utf8() });
+        i += c.len_utf8();
+    }
+
+    tokens
+}
+
+fn inner_attribute_text(src: &str, start: usize, end: usize) -> String {
+    let raw = &src[start..end];
+    let raw = raw.strip_prefix("#!").or_else(|| raw.strip_prefix('#')).unwrap_or(raw);
+    let raw = raw.strip_prefix('[').unwrap_or(raw);
+    raw.strip_suffix(']').unwrap_or(raw).trim().to_string()
+}
+
+fn peek(bytes: &[u8], i: usize) -> Option<char> {
+    bytes.get(i).map(|b| *b as char)
+}
+
+/// Decodes the full (possibly multi-byte) `char` starting at byte offset
+/// `i`, unlike `peek`, which only looks at a single raw byte and so
+/// misreads non-ASCII codepoints as unrelated Latin-1 characters.
+fn char_at(src: &str, i: usize) -> Option<char> {
+    src.get(i..)?.chars().next()
+}
+
+/// Advances from `start` (already past an identifier's first char) over
+/// the rest of a `char`-aware identifier, returning the end byte offset.
+fn scan_ident_continue(src: &str, start: usize) -> usize {
+    let mut j = start;
+    while let Some(c) = char_at(src, j) {
+        if !is_ident_continue(c) {
+            break;
+        }
+        j += c.len_utf8();
+    }
+    j
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_start_opt(c: Option<char>) -> bool {
+    c.map(is_ident_start).unwrap_or(false)
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_string_prefix(bytes: &[u8], i: usize) -> bool {
+    let has_b = peek(bytes, i) == Some('b');
+    let r_at = if has_b { i + 1 } else { i };
+    if peek(bytes, r_at) != Some('r') {
+        return false;
+    }
+    let mut j = r_at + 1;
+    while peek(bytes, j) == Some('#') {
+        j += 1;
+    }
+    peek(bytes, j) == Some('"')
+}
+
+fn lex_string(bytes: &[u8], i: usize) -> usize {
+    let len = bytes.len();
+    let mut j = i;
+    if peek(bytes, j) == Some('b') {
+        j += 1;
+    }
+    if peek(bytes, j) == Some('r') {
+        j += 1;
+        let mut hashes = 0usize;
+        while peek(bytes, j) == Some('#') {
+            hashes += 1;
+            j += 1;
+        }
+        if peek(bytes, j) != Some('"') {
+            return j;
+        }
+        j += 1;
+        loop {
+            if j >= len {
+                return j;
+            }
+            if bytes[j] as char == '"' {
+                let mut k = j + 1;
+                let mut count = 0usize;
+                while count < hashes && peek(bytes, k) == Some('#') {
+                    count += 1;
+                    k += 1;
+                }
+                if count == hashes {
+                    return k;
+                }
+            }
+            j += 1;
+        }
+    }
+    // Plain `"..."` with backslash escaping.
+    j += 1;
+    while j < len {
+        match bytes[j] as char {
+            '\\' => j += 2,
+            '"' => return j + 1,
+            _ => j += 1,
+        }
+    }
+    j
+}
+
+fn try_lex_char_literal(bytes: &[u8], i: usize) -> Option<usize> {
+    let len = bytes.len();
+    let mut j = i + 1;
+    if peek(bytes, j) == Some('\\') {
+        // Skip the backslash and the escaped char, then run to the closing
+        // quote so multi-char escapes like `'\u{41}'` stay one token.
+        j += 2;
+        while j < len && bytes[j] != b'\'' && bytes[j] != b'\n' {
+            j += 1;
+        }
+        if peek(bytes, j) == Some('\'') {
+            return Some(j + 1);
+        }
+        return None;
+    }
+    if j < len && peek(bytes, j + 1) == Some('\'') {
+        return Some(j + 2);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idents(tokens: &[Token]) -> Vec<&str> {
+        tokens
+            .iter()
+            .filter_map(|t| match &t.kind {
+                TokenKind::Ident { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lexes_raw_identifier() {
+        let tokens = tokenize("fn r#fn(r#match: bool) -> bool {}");
+        assert_eq!(idents(&tokens), vec!["fn", "fn", "match", "bool", "bool"]);
+        let raw_count = tokens
+            .iter()
+            .filter(|t| matches!(&t.kind, TokenKind::Ident { raw: true, .. }))
+            .count();
+        assert_eq!(raw_count, 2);
+    }
+
+    #[test]
+    fn lexes_doc_comment_vs_plain_comment() {
+        let tokens = tokenize("/// a breadcrumb\n// just a note\nfn f() {}");
+        assert!(matches!(&tokens[0].kind, TokenKind::DocComment(text) if text == "a breadcrumb"));
+        assert!(matches!(&tokens[1].kind, TokenKind::LineComment));
+    }
+
+    #[test]
+    fn does_not_split_braces_inside_string_literals() {
+        let tokens = tokenize(r#"fn f() { format!("{}_{}", a, b) }"#);
+        let open_braces = tokens.iter().filter(|t| t.kind == TokenKind::Punct('{')).count();
+        let close_braces = tokens.iter().filter(|t| t.kind == TokenKind::Punct('}')).count();
+        assert_eq!(open_braces, 1);
+        assert_eq!(close_braces, 1);
+    }
+
+    #[test]
+    fn lexes_attribute_as_one_token_with_its_path_and_args() {
+        let tokens = tokenize("#[doc(alias = \"lookup_value\")]\nfn f() {}");
+        assert!(matches!(
+            &tokens[0].kind,
+            TokenKind::Attribute { text, inner: false } if text == "doc(alias = \"lookup_value\")"
+        ));
+    }
+
+    #[test]
+    fn lexes_lifetime_without_treating_it_as_a_char_literal() {
+        let tokens = tokenize("fn f(x: &'static str) {}");
+        assert_eq!(idents(&tokens), vec!["fn", "f", "x", "static", "str"]);
+    }
+
+    #[test]
+    fn distinguishes_inner_from_outer_attributes() {
+        let tokens = tokenize("#![doc = \"crate docs\"]\n#[doc = \"item docs\"]\nfn f() {}");
+        assert!(matches!(&tokens[0].kind, TokenKind::Attribute { inner: true, .. }));
+        assert!(matches!(&tokens[1].kind, TokenKind::Attribute { inner: false, .. }));
+    }
+
+    #[test]
+    fn lexes_non_ascii_identifier_without_panicking() {
+        let tokens = tokenize("fn café() -> bool { true }");
+        assert_eq!(idents(&tokens), vec!["fn", "café", "bool", "true"]);
+    }
+}
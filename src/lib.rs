@@ -0,0 +1,4 @@
+pub mod diagnostics;
+pub mod lexer;
+pub mod lsp;
+pub mod parser;
@@ -0,0 +1,351 @@
+//! Breadcrumb coverage as a language server.
+//!
+//! Mirrors the RLS shape: a JSON-RPC 2.0 loop over stdio (`Content-Length`
+//! framed messages) that re-runs the breadcrumb scan on `didOpen`/
+//! `didChange`/`didSave`, publishes one `Diagnostic` per undocumented
+//! function/method, and answers `textDocument/codeAction` (insert a
+//! breadcrumb stub above the signature) and `textDocument/documentSymbol`
+//! (tag each symbol with its breadcrumb-present/absent state).
+//!
+//! A per-URI cache holds the last scanned text, its parsed function ranges,
+//! and the diagnostics last published for it, so `publishDiagnostics` is
+//! only sent again when the diagnostic set actually changed.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use serde_json::{json, Value};
+
+use crate::parser::{self, Item};
+
+struct DocumentState {
+    text: String,
+    items: Vec<Item>,
+    published: Vec<Value>,
+}
+
+/// Runs the JSON-RPC loop over stdin/stdout until `exit` or EOF.
+pub fn run_stdio() {
+    let stdin = io::stdin();
+    let mut reader = stdin.lock();
+    let mut stdout = io::stdout();
+    let mut docs: HashMap<String, DocumentState> = HashMap::new();
+
+    while let Some(msg) = read_message(&mut reader) {
+        if !handle_message(&msg, &mut docs, &mut stdout) {
+            break;
+        }
+    }
+}
+
+/// Returns `false` when the server should stop (an `exit` notification).
+fn handle_message<W: Write>(
+    msg: &Value,
+    docs: &mut HashMap<String, DocumentState>,
+    out: &mut W,
+) -> bool {
+    let method = msg.get("method").and_then(Value::as_str).unwrap_or("");
+    let id = msg.get("id").cloned();
+    let params = msg.get("params");
+
+    match method {
+        "initialize" => {
+            if let Some(id) = id {
+                send_response(
+                    out,
+                    id,
+                    json!({
+                        "capabilities": {
+                            "textDocumentSync": 1,
+                            "codeActionProvider": true,
+                            "documentSymbolProvider": true
+                        }
+                    }),
+                );
+            }
+        }
+        "textDocument/didOpen" => {
+            if let Some(params) = params {
+                let uri = text_document_uri(params, "textDocument");
+                let text = params["textDocument"]["text"].as_str().unwrap_or("").to_string();
+                update_document(docs, out, uri, text);
+            }
+        }
+        "textDocument/didChange" => {
+            if let Some(params) = params {
+                let uri = text_document_uri(params, "textDocument");
+                let text = params["contentChanges"][0]["text"].as_str().unwrap_or("").to_string();
+                update_document(docs, out, uri, text);
+            }
+        }
+        "textDocument/didSave" => {
+            if let Some(params) = params {
+                let uri = text_document_uri(params, "textDocument");
+                if let Some(text) = params["text"].as_str() {
+                    update_document(docs, out, uri, text.to_string());
+                } else if let Some(doc) = docs.get(&uri) {
+                    let text = doc.text.clone();
+                    update_document(docs, out, uri, text);
+                }
+            }
+        }
+        "textDocument/documentSymbol" => {
+            if let (Some(id), Some(params)) = (id, params) {
+                let uri = text_document_uri(params, "textDocument");
+                let symbols = docs.get(&uri).map(document_symbols).unwrap_or_default();
+                send_response(out, id, json!(symbols));
+            }
+        }
+        "textDocument/codeAction" => {
+            if let (Some(id), Some(params)) = (id, params) {
+                let uri = text_document_uri(params, "textDocument");
+                let range = params.get("range").cloned().unwrap_or_else(|| json!({}));
+                let actions = docs
+                    .get(&uri)
+                    .map(|doc| code_actions(&uri, doc, &range))
+                    .unwrap_or_default();
+                send_response(out, id, json!(actions));
+            }
+        }
+        "shutdown" => {
+            if let Some(id) = id {
+                send_response(out, id, Value::Null);
+            }
+        }
+        "exit" => return false,
+        _ => {}
+    }
+
+    true
+}
+
+fn text_document_uri(params: &Value, field: &str) -> String {
+    params[field]["uri"].as_str().unwrap_or("").to_string()
+}
+
+fn update_document<W: Write>(
+    docs: &mut HashMap<String, DocumentState>,
+    out: &mut W,
+    uri: String,
+    text: String,
+) {
+    let items = parser::scan(&text);
+    let diagnostics: Vec<Value> = items
+        .iter()
+        .filter(|item| !item.has_breadcrumb)
+        .map(|item| lsp_diagnostic(&text, item))
+        .collect();
+
+    let changed = docs.get(&uri).map(|d| d.published != diagnostics).unwrap_or(true);
+    if changed {
+        send_notification(
+            out,
+            "textDocument/publishDiagnostics",
+            json!({ "uri": uri, "diagnostics": diagnostics }),
+        );
+    }
+
+    docs.insert(
+        uri,
+        DocumentState {
+            text,
+            items,
+            published: diagnostics,
+        },
+    );
+}
+
+fn lsp_diagnostic(text: &str, item: &Item) -> Value {
+    let (start_line, start_char) = offset_to_position(text, item.name_span.start);
+    let (end_line, end_char) = offset_to_position(text, item.name_span.end);
+    json!({
+        "range": {
+            "start": { "line": start_line, "character": start_char },
+            "end": { "line": end_line, "character": end_char }
+        },
+        "severity": 2,
+        "source": "breadcrumb-detector",
+        "message": format!("`{}` is missing a breadcrumb", item.name),
+    })
+}
+
+fn document_symbols(doc: &DocumentState) -> Vec<Value> {
+    doc.items
+        .iter()
+        .map(|item| {
+            let (start_line, start_char) = offset_to_position(&doc.text, item.sig_span.start);
+            let (end_line, end_char) = offset_to_position(&doc.text, item.sig_span.end);
+            let (name_start_line, name_start_char) = offset_to_position(&doc.text, item.name_span.start);
+            let (name_end_line, name_end_char) = offset_to_position(&doc.text, item.name_span.end);
+            let detail = if item.has_breadcrumb {
+                "breadcrumb: present"
+            } else {
+                "breadcrumb: absent"
+            };
+            json!({
+                "name": item.name,
+                "detail": detail,
+                "kind": 12,
+                "range": {
+                    "start": { "line": start_line, "character": start_char },
+                    "end": { "line": end_line, "character": end_char }
+                },
+                "selectionRange": {
+                    "start": { "line": name_start_line, "character": name_start_char },
+                    "end": { "line": name_end_line, "character": name_end_char }
+                }
+            })
+        })
+        .collect()
+}
+
+fn code_actions(uri: &str, doc: &DocumentState, range: &Value) -> Vec<Value> {
+    let start_offset = range
+        .get("start")
+        .map(|pos| position_to_offset(&doc.text, pos))
+        .unwrap_or(0);
+
+    doc.items
+        .iter()
+        .filter(|item| !item.has_breadcrumb)
+        .filter(|item| item.sig_span.start <= start_offset && start_offset <= item.sig_span.end)
+        .map(|item| {
+            let (line, _) = offset_to_position(&doc.text, item.sig_span.start);
+            let indent = leading_whitespace(&doc.text, item.sig_span.start);
+            let stub = format!("{indent}/// TODO: breadcrumb for `{}`.\n", item.name);
+            json!({
+                "title": format!("Insert breadcrumb stub for `{}`", item.name),
+                "kind": "quickfix",
+                "edit": {
+                    "changes": {
+                        uri: [{
+                            "range": {
+                                "start": { "line": line, "character": 0 },
+                                "end": { "line": line, "character": 0 }
+                            },
+                            "newText": stub
+                        }]
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+fn leading_whitespace(text: &str, offset: usize) -> String {
+    let line_start = text[..offset].rfind('\n').map(|p| p + 1).unwrap_or(0);
+    text[line_start..offset].chars().take_while(|c| c.is_whitespace()).collect()
+}
+
+fn offset_to_position(text: &str, offset: usize) -> (u64, u64) {
+    let mut line = 0u64;
+    let mut character = 0u64;
+    for (i, ch) in text.char_indices() {
+        if i >= offset {
+            break;
+        }
+        if ch == '\n' {
+            line += 1;
+            character = 0;
+        } else {
+            character += 1;
+        }
+    }
+    (line, character)
+}
+
+fn position_to_offset(text: &str, pos: &Value) -> usize {
+    let target_line = pos.get("line").and_then(Value::as_u64).unwrap_or(0);
+    let target_char = pos.get("character").and_then(Value::as_u64).unwrap_or(0);
+    let mut line = 0u64;
+    let mut character = 0u64;
+    for (i, ch) in text.char_indices() {
+        if line == target_line && character == target_char {
+            return i;
+        }
+        if ch == '\n' {
+            if line == target_line {
+                return i;
+            }
+            line += 1;
+            character = 0;
+        } else {
+            character += 1;
+        }
+    }
+    text.len()
+}
+
+fn read_message<R: BufRead>(reader: &mut R) -> Option<Value> {
+    let mut content_length: Option<usize> = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let trimmed = line.trim_end_matches(['\r', '\n']);
+        if trimmed.is_empty() {
+            break;
+        }
+        if let Some(rest) = trimmed.strip_prefix("Content-Length:") {
+            content_length = rest.trim().parse().ok();
+        }
+    }
+    let len = content_length?;
+    let mut buf = vec![0u8; len];
+    reader.read_exact(&mut buf).ok()?;
+    serde_json::from_slice(&buf).ok()
+}
+
+fn write_message<W: Write>(writer: &mut W, value: &Value) {
+    let body = serde_json::to_vec(value).unwrap_or_default();
+    let header = format!("Content-Length: {}\r\n\r\n", body.len());
+    let _ = writer.write_all(header.as_bytes());
+    let _ = writer.write_all(&body);
+    let _ = writer.flush();
+}
+
+fn send_response<W: Write>(writer: &mut W, id: Value, result: Value) {
+    write_message(writer, &json!({ "jsonrpc": "2.0", "id": id, "result": result }));
+}
+
+fn send_notification<W: Write>(writer: &mut W, method: &str, params: Value) {
+    write_message(
+        writer,
+        &json!({ "jsonrpc": "2.0", "method": method, "params": params }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn publishes_once_for_unchanged_rescans() {
+        let mut docs = HashMap::new();
+        let mut out: Vec<u8> = Vec::new();
+        let text = "fn undocumented_function() -> bool {\n    true\n}\n".to_string();
+
+        update_document(&mut docs, &mut out, "file:///a.rs".to_string(), text.clone());
+        let first_len = out.len();
+        update_document(&mut docs, &mut out, "file:///a.rs".to_string(), text);
+
+        assert_eq!(out.len(), first_len, "no new notification for an unchanged diagnostic set");
+    }
+
+    #[test]
+    fn code_action_targets_the_right_function() {
+        let mut docs = HashMap::new();
+        let mut out: Vec<u8> = Vec::new();
+        let text = "fn undocumented_function() -> bool {\n    true\n}\n".to_string();
+        update_document(&mut docs, &mut out, "file:///a.rs".to_string(), text);
+
+        let actions = code_actions(
+            "file:///a.rs",
+            docs.get("file:///a.rs").unwrap(),
+            &json!({ "start": { "line": 0, "character": 3 } }),
+        );
+        assert_eq!(actions.len(), 1);
+        assert!(actions[0]["title"].as_str().unwrap().contains("undocumented_function"));
+    }
+}
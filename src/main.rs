@@ -0,0 +1,56 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use breadcrumb_detector::{diagnostics, lsp, parser};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.iter().any(|a| a == "--lsp") {
+        lsp::run_stdio();
+        return;
+    }
+
+    let mut format = "text".to_string();
+    let mut path: Option<String> = None;
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--format" => {
+                i += 1;
+                format = args.get(i).cloned().unwrap_or_else(|| "text".to_string());
+            }
+            other => path = Some(other.to_string()),
+        }
+        i += 1;
+    }
+
+    let path = match path {
+        Some(p) => p,
+        None => {
+            eprintln!("usage: breadcrumb-detector [--lsp] [--format text|json] <file>");
+            process::exit(2);
+        }
+    };
+
+    let src = match fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(err) => {
+            eprintln!("failed to read {path}: {err}");
+            process::exit(1);
+        }
+    };
+
+    let items = parser::scan(&src);
+    let diags = diagnostics::diagnostics_for_file(&path, &items);
+
+    match format.as_str() {
+        "json" => println!("{}", diagnostics::render_json(&diags)),
+        _ => print!("{}", diagnostics::render_human(&diags)),
+    }
+
+    if !diags.is_empty() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,601 @@
+//! Per-function breadcrumb scan.
+//!
+//! Walks the token stream from [`crate::lexer`] to find function/method
+//! signatures, stripping leading modifiers (`pub`, `pub(crate)`, `async`,
+//! `const`, `unsafe`, `extern "C"`) in any order, recognizing `r#`-prefixed
+//! raw identifiers, and balancing `<...>`/`(...)` so a `Vec<String>` return
+//! type can't end a signature early. Each item is tagged with its
+//! [`ItemKind`] — free function, inherent method, or trait-impl method —
+//! by tracking the enclosing `impl`/`trait` block.
+//!
+//! A signature counts as breadcrumbed when it's immediately preceded
+//! (blank lines allowed, other items not) by a `///` doc comment, a
+//! `#[doc = "..."]`/`#[doc(alias = "...")]` attribute, or a configurable
+//! custom attribute such as `#[breadcrumb(...)]`; any `alias` values on
+//! those attributes are captured onto the item.
+
+use crate::lexer::{self, Token, TokenKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    Function,
+    InherentMethod,
+    TraitImplMethod,
+    TraitMethodSignature,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    #[default]
+    Private,
+    Crate,
+    Pub,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    pub is_async: bool,
+    pub is_const: bool,
+    pub is_unsafe: bool,
+    pub extern_abi: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BreadcrumbKind {
+    DocComment,
+    DocAttribute,
+    CustomAttribute(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Breadcrumb {
+    pub kind: Option<BreadcrumbKind>,
+    pub aliases: Vec<String>,
+}
+
+impl Breadcrumb {
+    pub fn is_present(&self) -> bool {
+        self.kind.is_some()
+    }
+}
+
+/// Tunables for what counts as a breadcrumb beyond `///` doc comments and
+/// `#[doc(...)]` attributes.
+pub struct ParserConfig {
+    pub custom_breadcrumb_attr: String,
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self { custom_breadcrumb_attr: "breadcrumb".to_string() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Item {
+    pub kind: ItemKind,
+    pub name: String,
+    pub is_raw_identifier: bool,
+    pub visibility: Visibility,
+    pub modifiers: Modifiers,
+    pub name_span: Span,
+    pub sig_span: Span,
+    pub breadcrumb: Breadcrumb,
+    // Preserved for the LSP layer, which only cares whether a breadcrumb
+    // is present, not how it was spelled.
+    pub has_breadcrumb: bool,
+}
+
+enum Context {
+    ImplInherent,
+    ImplTrait,
+    TraitDecl,
+}
+
+struct ContextFrame {
+    kind: Context,
+    depth_at_enter: i32,
+}
+
+pub fn scan(src: &str) -> Vec<Item> {
+    scan_with_config(src, &ParserConfig::default())
+}
+
+pub fn scan_with_config(src: &str, config: &ParserConfig) -> Vec<Item> {
+    let tokens = lexer::tokenize(src);
+    let mut items = Vec::new();
+    let mut trivia: Vec<usize> = Vec::new();
+    let mut context_stack: Vec<ContextFrame> = Vec::new();
+    let mut depth: i32 = 0;
+    let mut i = 0usize;
+
+    while i < tokens.len() {
+        match &tokens[i].kind {
+            TokenKind::DocComment(_) | TokenKind::Attribute { inner: false, .. } => {
+                trivia.push(i);
+                i += 1;
+            }
+            TokenKind::LineComment | TokenKind::Attribute { inner: true, .. } => {
+                // Plain comments are not breadcrumbs, and `#![...]` inner
+                // attributes document the *enclosing* scope rather than
+                // the item that follows — neither counts as a breadcrumb
+                // for the next item, but (unlike other items) they don't
+                // break an existing trivia run either.
+                i += 1;
+            }
+            TokenKind::Punct('{') => {
+                depth += 1;
+                i += 1;
+            }
+            TokenKind::Punct('}') => {
+                depth -= 1;
+                if matches!(context_stack.last(), Some(f) if f.depth_at_enter == depth) {
+                    context_stack.pop();
+                }
+                i += 1;
+            }
+            TokenKind::Ident { text, raw: false } if text == "impl" => {
+                let has_for = i + 1 < tokens.len() && {
+                    let mut j = i + 1;
+                    let mut saw_for = false;
+                    while j < tokens.len() {
+                        match &tokens[j].kind {
+                            TokenKind::Punct('<') => j = skip_balanced(&tokens, j, '<', '>').unwrap_or(j + 1),
+                            TokenKind::Ident { text, raw: false } if text == "for" => {
+                                saw_for = true;
+                                j += 1;
+                            }
+                            TokenKind::Punct('{') => break,
+                            _ => j += 1,
+                        }
+                    }
+                    i = j;
+                    saw_for
+                };
+                context_stack.push(ContextFrame {
+                    kind: if has_for { Context::ImplTrait } else { Context::ImplInherent },
+                    depth_at_enter: depth,
+                });
+                trivia.clear();
+            }
+            TokenKind::Ident { text, raw: false } if text == "trait" => {
+                let mut j = i + 1;
+                while j < tokens.len() {
+                    match &tokens[j].kind {
+                        TokenKind::Punct('<') => j = skip_balanced(&tokens, j, '<', '>').unwrap_or(j + 1),
+                        TokenKind::Punct('{') => break,
+                        _ => j += 1,
+                    }
+                }
+                i = j;
+                context_stack.push(ContextFrame { kind: Context::TraitDecl, depth_at_enter: depth });
+                trivia.clear();
+            }
+            TokenKind::Ident { text, raw: false } if is_fn_start_keyword(text) => {
+                match try_parse_fn(&tokens, i, src, &context_stack, &trivia, config) {
+                    Some((item, next_i)) => {
+                        items.push(item);
+                        trivia.clear();
+                        i = next_i;
+                    }
+                    None => {
+                        // A modifier sequence that doesn't lead to `fn` is
+                        // some other item (e.g. `const NAME: Type = ...;`):
+                        // it breaks trivia association like any other item.
+                        trivia.clear();
+                        i += 1;
+                    }
+                }
+            }
+            _ => {
+                if !trivia.is_empty() {
+                    trivia.clear();
+                }
+                i += 1;
+            }
+        }
+    }
+
+    items
+}
+
+fn is_fn_start_keyword(text: &str) -> bool {
+    matches!(text, "pub" | "async" | "const" | "unsafe" | "extern" | "fn")
+}
+
+fn try_parse_fn(
+    tokens: &[Token],
+    start: usize,
+    src: &str,
+    context_stack: &[ContextFrame],
+    trivia: &[usize],
+    config: &ParserConfig,
+) -> Option<(Item, usize)> {
+    let mut idx = start;
+    let mut visibility = Visibility::Private;
+    let mut modifiers = Modifiers::default();
+
+    loop {
+        match &tokens.get(idx)?.kind {
+            TokenKind::Ident { text, raw: false } if text == "pub" => {
+                idx += 1;
+                if matches!(tokens.get(idx).map(|t| &t.kind), Some(TokenKind::Punct('('))) {
+                    visibility = Visibility::Crate;
+                    idx = skip_balanced(tokens, idx, '(', ')')?;
+                } else {
+                    visibility = Visibility::Pub;
+                }
+            }
+            TokenKind::Ident { text, raw: false } if text == "async" => {
+                modifiers.is_async = true;
+                idx += 1;
+            }
+            TokenKind::Ident { text, raw: false } if text == "const" => {
+                modifiers.is_const = true;
+                idx += 1;
+            }
+            TokenKind::Ident { text, raw: false } if text == "unsafe" => {
+                modifiers.is_unsafe = true;
+                idx += 1;
+            }
+            TokenKind::Ident { text, raw: false } if text == "extern" => {
+                idx += 1;
+                if let Some(Token { kind: TokenKind::Literal, start: s, end: e }) = tokens.get(idx) {
+                    modifiers.extern_abi = Some(src[*s..*e].trim_matches('"').to_string());
+                    idx += 1;
+                } else {
+                    modifiers.extern_abi = Some("C".to_string());
+                }
+            }
+            TokenKind::Ident { text, raw: false } if text == "fn" => {
+                idx += 1;
+                break;
+            }
+            _ => return None,
+        }
+    }
+
+    let (name, is_raw, name_span) = match &tokens.get(idx)?.kind {
+        TokenKind::Ident { text, raw } => {
+            let span = Span { start: tokens[idx].start, end: tokens[idx].end };
+            (text.clone(), *raw, span)
+        }
+        _ => return None,
+    };
+    idx += 1;
+
+    if matches!(tokens.get(idx).map(|t| &t.kind), Some(TokenKind::Punct('<'))) {
+        idx = skip_balanced(tokens, idx, '<', '>')?;
+    }
+
+    if !matches!(tokens.get(idx).map(|t| &t.kind), Some(TokenKind::Punct('('))) {
+        return None;
+    }
+    idx = skip_balanced(tokens, idx, '(', ')')?;
+
+    if matches!(tokens.get(idx).map(|t| &t.kind), Some(TokenKind::Arrow)) {
+        idx += 1;
+        idx = skip_return_type(tokens, idx)?;
+    }
+
+    if matches!(tokens.get(idx).map(|t| &t.kind), Some(TokenKind::Ident { text, .. }) if text == "where") {
+        idx = skip_where_clause(tokens, idx)?;
+    }
+
+    let sig_end_token = tokens.get(idx)?;
+    if !matches!(sig_end_token.kind, TokenKind::Punct('{') | TokenKind::Punct(';')) {
+        return None;
+    }
+
+    let kind = match context_stack.last().map(|f| &f.kind) {
+        Some(Context::ImplTrait) => ItemKind::TraitImplMethod,
+        Some(Context::ImplInherent) => ItemKind::InherentMethod,
+        Some(Context::TraitDecl) => ItemKind::TraitMethodSignature,
+        None => ItemKind::Function,
+    };
+
+    let breadcrumb = resolve_breadcrumb(tokens, trivia, config);
+
+    Some((
+        Item {
+            kind,
+            name,
+            is_raw_identifier: is_raw,
+            visibility,
+            modifiers,
+            name_span,
+            sig_span: Span { start: tokens[start].start, end: sig_end_token.start },
+            has_breadcrumb: breadcrumb.is_present(),
+            breadcrumb,
+        },
+        idx,
+    ))
+}
+
+fn skip_return_type(tokens: &[Token], mut idx: usize) -> Option<usize> {
+    while let Some(t) = tokens.get(idx) {
+        match &t.kind {
+            TokenKind::Punct('<') => idx = skip_balanced(tokens, idx, '<', '>')?,
+            TokenKind::Punct('(') => idx = skip_balanced(tokens, idx, '(', ')')?,
+            TokenKind::Punct('{') | TokenKind::Punct(';') => return Some(idx),
+            TokenKind::Ident { text, .. } if text == "where" => return Some(idx),
+            _ => idx += 1,
+        }
+    }
+    Some(idx)
+}
+
+fn skip_where_clause(tokens: &[Token], mut idx: usize) -> Option<usize> {
+    while let Some(t) = tokens.get(idx) {
+        match &t.kind {
+            TokenKind::Punct('<') => idx = skip_balanced(tokens, idx, '<', '>')?,
+            TokenKind::Punct('{') | TokenKind::Punct(';') => return Some(idx),
+            _ => idx += 1,
+        }
+    }
+    Some(idx)
+}
+
+fn skip_balanced(tokens: &[Token], idx: usize, open: char, close: char) -> Option<usize> {
+    let mut depth = 0i32;
+    let mut j = idx;
+    loop {
+        match tokens.get(j).map(|t| &t.kind) {
+            Some(TokenKind::Punct(c)) if *c == open => {
+                depth += 1;
+                j += 1;
+            }
+            Some(TokenKind::Punct(c)) if *c == close => {
+                depth -= 1;
+                j += 1;
+                if depth == 0 {
+                    return Some(j);
+                }
+            }
+            Some(_) => j += 1,
+            None => return None,
+        }
+    }
+}
+
+fn resolve_breadcrumb(tokens: &[Token], trivia: &[usize], config: &ParserConfig) -> Breadcrumb {
+    let mut kind = None;
+    let mut aliases = Vec::new();
+
+    for &idx in trivia {
+        match &tokens[idx].kind {
+            TokenKind::DocComment(_) => {
+                kind.get_or_insert(BreadcrumbKind::DocComment);
+            }
+            TokenKind::Attribute { text, .. } => {
+                let path = attribute_path(text);
+                if path == "doc" {
+                    kind.get_or_insert(BreadcrumbKind::DocAttribute);
+                    aliases.extend(extract_key_values(text, "alias"));
+                } else if path == config.custom_breadcrumb_attr {
+                    kind.get_or_insert(BreadcrumbKind::CustomAttribute(path));
+                    aliases.extend(extract_key_values(text, "alias"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Breadcrumb { kind, aliases }
+}
+
+/// Returns the leading identifier path of an attribute's inner text, e.g.
+/// `"doc"` for `doc(alias = "x")` or `doc = "x"`.
+fn attribute_path(inner: &str) -> String {
+    inner
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == ':')
+        .collect()
+}
+
+/// Scans an attribute's inner text for `key = "value"` occurrences
+/// (ignoring whitespace around `=`), returning every matching value in
+/// order. Used to pull `alias = "..."` values out of `#[doc(...)]` and
+/// custom breadcrumb attributes.
+fn extract_key_values(text: &str, key: &str) -> Vec<String> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::new();
+    let mut i = 0usize;
+
+    while let Some(rel) = text[i..].find(key) {
+        let start = i + rel;
+        let before_ok = start == 0 || !is_word_char(bytes[start - 1] as char);
+        let mut j = start + key.len();
+        let after_ok = j >= bytes.len() || !is_word_char(bytes[j] as char);
+
+        if before_ok && after_ok {
+            while j < bytes.len() && (bytes[j] as char).is_whitespace() {
+                j += 1;
+            }
+            if j < bytes.len() && bytes[j] as char == '=' {
+                j += 1;
+                while j < bytes.len() && (bytes[j] as char).is_whitespace() {
+                    j += 1;
+                }
+                if j < bytes.len() && bytes[j] as char == '"' {
+                    let value_start = j + 1;
+                    let mut k = value_start;
+                    while k < bytes.len() && bytes[k] as char != '"' {
+                        k += 1;
+                    }
+                    out.push(text[value_start..k].to_string());
+                    i = k + 1;
+                    continue;
+                }
+            }
+        }
+        i = start + key.len();
+    }
+
+    out
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../codebase_summary/language_scan_tests/test_rust_functions.rs");
+
+    fn find<'a>(items: &'a [Item], name: &str) -> &'a Item {
+        items.iter().find(|i| i.name == name).unwrap_or_else(|| panic!("missing item `{name}`"))
+    }
+
+    #[test]
+    fn free_function_is_undocumented() {
+        let items = scan(FIXTURE);
+        let item = find(&items, "undocumented_function");
+        assert_eq!(item.kind, ItemKind::Function);
+        assert!(!item.breadcrumb.is_present());
+    }
+
+    #[test]
+    fn raw_identifier_function_name_is_recognized() {
+        let items = scan(FIXTURE);
+        let item = find(&items, "fn");
+        assert!(item.is_raw_identifier);
+    }
+
+    #[test]
+    fn raw_identifier_spelling_a_keyword_is_not_mistaken_for_a_modifier() {
+        let items = scan("fn uses_raw_async(r#async: bool) -> bool {\n    r#async\n}\n");
+        let item = find(&items, "uses_raw_async");
+        assert!(!item.modifiers.is_async);
+        assert_eq!(item.kind, ItemKind::Function);
+    }
+
+    #[test]
+    fn modifier_combinations_are_stripped() {
+        let items = scan(FIXTURE);
+
+        let unsafe_fn = find(&items, "unsafe_function");
+        assert!(unsafe_fn.modifiers.is_unsafe);
+
+        let extern_fn = find(&items, "extern_function");
+        assert_eq!(extern_fn.modifiers.extern_abi.as_deref(), Some("C"));
+
+        let combined = find(&items, "combined_modifiers_function");
+        assert!(combined.modifiers.is_async);
+        assert_eq!(combined.visibility, Visibility::Pub);
+
+        let crate_fn = find(&items, "crate_visible_function");
+        assert_eq!(crate_fn.visibility, Visibility::Crate);
+
+        let const_fn = find(&items, "const_function");
+        assert!(const_fn.modifiers.is_const);
+    }
+
+    #[test]
+    fn nested_generic_return_type_does_not_truncate_signature() {
+        let items = scan(FIXTURE);
+        let item = find(&items, "nested_generic_function");
+        // If the `<...>` in the parameter type or the `->` return type were
+        // mishandled, the signature span would end inside the generics
+        // instead of at the function's opening brace.
+        let sig_text = &FIXTURE[item.sig_span.start..item.sig_span.end];
+        assert!(sig_text.contains("Vec<HashMap<String, T>>"), "signature was truncated: {sig_text:?}");
+        assert!(sig_text.contains("-> usize"), "signature was truncated: {sig_text:?}");
+    }
+
+    #[test]
+    fn distinguishes_free_functions_inherent_and_trait_impl_methods() {
+        let items = scan(FIXTURE);
+        assert_eq!(find(&items, "basic_function").kind, ItemKind::Function);
+        assert_eq!(find(&items, "new").kind, ItemKind::InherentMethod);
+
+        // `trait_method` appears twice: once as the trait declaration's
+        // signature, once as `impl TestTrait for TestStruct`'s definition.
+        let trait_methods: Vec<_> = items.iter().filter(|i| i.name == "trait_method").collect();
+        assert_eq!(trait_methods.len(), 2);
+        assert_eq!(trait_methods[0].kind, ItemKind::TraitMethodSignature);
+        assert_eq!(trait_methods[1].kind, ItemKind::TraitImplMethod);
+    }
+
+    #[test]
+    fn trait_declaration_method_has_no_body() {
+        let items = scan("trait T {\n    fn trait_method(&self) -> String;\n}\n");
+        let item = find(&items, "trait_method");
+        assert_eq!(item.kind, ItemKind::TraitMethodSignature);
+    }
+
+    #[test]
+    fn doc_comment_counts_as_breadcrumb() {
+        let items = scan(FIXTURE);
+        let item = find(&items, "doc_comment_function");
+        assert_eq!(item.breadcrumb.kind, Some(BreadcrumbKind::DocComment));
+    }
+
+    #[test]
+    fn doc_attribute_counts_as_breadcrumb() {
+        let items = scan(FIXTURE);
+        let item = find(&items, "doc_attribute_function");
+        assert_eq!(item.breadcrumb.kind, Some(BreadcrumbKind::DocAttribute));
+    }
+
+    #[test]
+    fn stacked_doc_alias_attributes_are_captured() {
+        let items = scan(FIXTURE);
+        let item = find(&items, "aliased_function");
+        assert!(item.breadcrumb.is_present());
+        assert_eq!(item.breadcrumb.aliases, vec!["lookup_value", "find_value"]);
+    }
+
+    #[test]
+    fn custom_breadcrumb_attribute_is_configurable_and_captures_alias() {
+        let items = scan(FIXTURE);
+        let item = find(&items, "custom_breadcrumb_function");
+        assert_eq!(
+            item.breadcrumb.kind,
+            Some(BreadcrumbKind::CustomAttribute("breadcrumb".to_string()))
+        );
+        assert_eq!(item.breadcrumb.aliases, vec!["custom_lookup"]);
+    }
+
+    #[test]
+    fn attribute_stack_survives_a_blank_line_before_the_signature() {
+        let src = "#[breadcrumb(id = \"x\")]\n\nfn f() {}\n";
+        let items = scan(src);
+        assert!(find(&items, "f").breadcrumb.is_present());
+    }
+
+    #[test]
+    fn another_item_between_attribute_and_signature_breaks_association() {
+        let src = "#[breadcrumb(id = \"x\")]\nstruct Unrelated;\nfn f() {}\n";
+        let items = scan(src);
+        assert!(!find(&items, "f").breadcrumb.is_present());
+    }
+
+    #[test]
+    fn crate_level_inner_doc_attribute_does_not_breadcrumb_the_next_item() {
+        let src = "#![doc = \"Crate-level documentation, not a breadcrumb for foo.\"]\n\nfn foo() -> bool { true }\n";
+        let items = scan(src);
+        assert!(!find(&items, "foo").breadcrumb.is_present());
+    }
+
+    #[test]
+    fn custom_breadcrumb_attribute_name_is_configurable() {
+        let src = "#[my_marker(alias = \"x\")]\nfn f() {}\n";
+        let default_config = ParserConfig::default();
+        assert!(!scan_with_config(src, &default_config)[0].breadcrumb.is_present());
+
+        let custom_config = ParserConfig { custom_breadcrumb_attr: "my_marker".to_string() };
+        let items = scan_with_config(src, &custom_config);
+        assert!(items[0].breadcrumb.is_present());
+        assert_eq!(items[0].breadcrumb.aliases, vec!["x"]);
+    }
+}